@@ -1,12 +1,45 @@
-use core::ops::{Add, AddAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
 #[cfg(feature = "std")]
 mod standard;
-
-pub const KB: u64 = 1 << 10;
-pub const MB: u64 = 1 << 20;
-pub const GB: u64 = 1 << 30;
-pub const TB: u64 = 1 << 40;
+#[cfg(feature = "std")]
+pub use standard::*;
+
+/// IEC (binary, 1024-based) kibibyte.
+pub const KIB: u64 = 1 << 10;
+/// IEC (binary, 1024-based) mebibyte.
+pub const MIB: u64 = 1 << 20;
+/// IEC (binary, 1024-based) gibibyte.
+pub const GIB: u64 = 1 << 30;
+/// IEC (binary, 1024-based) tebibyte.
+pub const TIB: u64 = 1 << 40;
+/// IEC (binary, 1024-based) pebibyte.
+pub const PIB: u64 = 1 << 50;
+/// IEC (binary, 1024-based) exbibyte.
+pub const EIB: u64 = 1 << 60;
+
+/// SI (decimal, 1000-based) kilobyte.
+pub const KB: u64 = 1_000;
+/// SI (decimal, 1000-based) megabyte.
+pub const MB: u64 = 1_000 * KB;
+/// SI (decimal, 1000-based) gigabyte.
+pub const GB: u64 = 1_000 * MB;
+/// SI (decimal, 1000-based) terabyte.
+pub const TB: u64 = 1_000 * GB;
+/// SI (decimal, 1000-based) petabyte.
+pub const PB: u64 = 1_000 * TB;
+/// SI (decimal, 1000-based) exabyte.
+pub const EB: u64 = 1_000 * PB;
+
+/// Unit system a [ByteSize] was expressed in, used to pick the right
+/// suffix (`kB` vs `KiB`, ...) and divisor when displaying a value.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum System {
+    /// SI, decimal, 1000-based units (`kB`, `MB`, `GB`, `TB`)
+    Si,
+    /// IEC, binary, 1024-based units (`KiB`, `MiB`, `GiB`, `TiB`)
+    Iec,
+}
 
 #[derive(Clone, Copy)]
 pub enum Unit {
@@ -15,6 +48,8 @@ pub enum Unit {
     Mega,
     Giga,
     Tera,
+    Peta,
+    Exa,
 }
 
 impl From<Size> for Unit {
@@ -22,10 +57,12 @@ impl From<Size> for Unit {
     fn from(value: Size) -> Self {
         match value {
             Size::Bytes(_) => Self::Bytes,
-            Size::Kilo(_) => Self::Kilo,
-            Size::Mega(_) => Self::Mega,
-            Size::Giga(_) => Self::Giga,
-            Size::Tera(_) => Self::Tera,
+            Size::Kilo(..) => Self::Kilo,
+            Size::Mega(..) => Self::Mega,
+            Size::Giga(..) => Self::Giga,
+            Size::Tera(..) => Self::Tera,
+            Size::Peta(..) => Self::Peta,
+            Size::Exa(..) => Self::Exa,
         }
     }
 }
@@ -53,15 +90,20 @@ impl From<Size> for Unit {
 pub struct ByteSize(Size);
 
 /// Enum encoding a size in bytes, the data carried by
-/// the enum is always a value expressed **in bytes**.
+/// the enum is always a value expressed **in bytes**. Every
+/// variant but [Size::Bytes] also carries the [System] it was
+/// expressed in, so that the value can be displayed back in the
+/// same unit system it came from.
 #[derive(Clone, Copy)]
 #[repr(u64)]
 enum Size {
     Bytes(u64),
-    Kilo(u64),
-    Mega(u64),
-    Giga(u64),
-    Tera(u64),
+    Kilo(u64, System),
+    Mega(u64, System),
+    Giga(u64, System),
+    Tera(u64, System),
+    Peta(u64, System),
+    Exa(u64, System),
 }
 
 impl Default for Size {
@@ -76,30 +118,85 @@ impl Add for ByteSize {
     type Output = Self;
     #[inline(always)]
     fn add(self, rhs: Self) -> Self::Output {
-        Self::from_bytes(self.in_bytes() + rhs.in_bytes())
+        self.saturating_add(rhs)
     }
 }
 
 impl Sub for ByteSize {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
-        Self::from_bytes(self.in_bytes() - rhs.in_bytes())
+        self.saturating_sub(rhs)
     }
 }
 
 impl AddAssign for ByteSize {
     #[inline(always)]
     fn add_assign(&mut self, rhs: Self) {
-        let res = self.in_bytes() + rhs.in_bytes();
-        *self = Self::from_bytes(res)
+        *self = self.saturating_add(rhs)
     }
 }
 
 impl SubAssign for ByteSize {
     #[inline(always)]
     fn sub_assign(&mut self, rhs: Self) {
-        let res = self.in_bytes() - rhs.in_bytes();
-        *self = Self::from_bytes(res)
+        *self = self.saturating_sub(rhs)
+    }
+}
+
+impl Mul<u64> for ByteSize {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: u64) -> Self::Output {
+        Self::from_bytes(self.in_bytes().saturating_mul(rhs))
+    }
+}
+
+impl Mul<f64> for ByteSize {
+    type Output = Self;
+    #[inline(always)]
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::from_bytes((self.in_bytes() as f64 * rhs).round() as u64)
+    }
+}
+
+impl MulAssign<u64> for ByteSize {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: u64) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<f64> for ByteSize {
+    #[inline(always)]
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<u64> for ByteSize {
+    type Output = Self;
+    #[inline(always)]
+    fn div(self, rhs: u64) -> Self::Output {
+        // dividing by zero saturates to u64::MAX bytes instead of panicking,
+        // consistent with the other operators never panicking on size math
+        Self::from_bytes(self.in_bytes().checked_div(rhs).unwrap_or(u64::MAX))
+    }
+}
+
+impl DivAssign<u64> for ByteSize {
+    #[inline(always)]
+    fn div_assign(&mut self, rhs: u64) {
+        *self = *self / rhs;
+    }
+}
+
+/// Dividing a [ByteSize] by another one yields the ratio between the two,
+/// handy to compute utilization percentages.
+impl Div for ByteSize {
+    type Output = f64;
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self.in_bytes() as f64 / rhs.in_bytes() as f64
     }
 }
 
@@ -125,104 +222,480 @@ impl ByteSize {
         Self::from_bytes(b / 8)
     }
 
-    /// Creates a [ByteSize] from a given number of bytes.
+    /// Adds two [ByteSize], returning `None` instead of overflowing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use huby::ByteSize;
+    ///
+    /// assert_eq!(
+    ///     ByteSize::from_bytes(1).checked_add(ByteSize::from_bytes(1)),
+    ///     Some(ByteSize::from_bytes(2))
+    /// );
+    /// assert!(ByteSize::from_bytes(u64::MAX)
+    ///     .checked_add(ByteSize::from_bytes(1))
+    ///     .is_none());
+    /// ```
+    #[inline(always)]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.in_bytes().checked_add(rhs.in_bytes()) {
+            Some(b) => Some(Self::from_bytes(b)),
+            None => None,
+        }
+    }
+
+    /// Subtracts `rhs` from `self`, returning `None` instead of underflowing.
     ///
     /// # Example
     ///
     /// ```
     /// use huby::ByteSize;
     ///
-    /// assert_eq!(ByteSize::from_bytes(1024), ByteSize::from_kb(1));
-    /// assert_eq!(ByteSize::from_bytes(4096), ByteSize::from_kb(4));
+    /// assert_eq!(
+    ///     ByteSize::from_bytes(2).checked_sub(ByteSize::from_bytes(1)),
+    ///     Some(ByteSize::from_bytes(1))
+    /// );
+    /// assert!(ByteSize::from_bytes(0)
+    ///     .checked_sub(ByteSize::from_bytes(1))
+    ///     .is_none());
+    /// ```
+    #[inline(always)]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.in_bytes().checked_sub(rhs.in_bytes()) {
+            Some(b) => Some(Self::from_bytes(b)),
+            None => None,
+        }
+    }
+
+    /// Adds two [ByteSize], clamping to `u64::MAX` bytes instead of overflowing.
+    #[inline(always)]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self::from_bytes(self.in_bytes().saturating_add(rhs.in_bytes()))
+    }
+
+    /// Subtracts `rhs` from `self`, clamping to `0` bytes instead of underflowing.
+    #[inline(always)]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self::from_bytes(self.in_bytes().saturating_sub(rhs.in_bytes()))
+    }
+
+    /// Creates a [ByteSize] from a given number of bytes, normalizing
+    /// to the IEC (binary) unit system.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use huby::ByteSize;
+    ///
+    /// assert_eq!(ByteSize::from_bytes(1024), ByteSize::from_kib(1));
+    /// assert_eq!(ByteSize::from_bytes(4096), ByteSize::from_kib(4));
     /// ```
     #[inline(always)]
     pub const fn from_bytes(b: u64) -> Self {
+        Self::from_bytes_iec(b)
+    }
+
+    /// Creates a [ByteSize] from a given number of bytes, normalizing
+    /// to the SI (decimal) unit system.
+    #[inline(always)]
+    pub const fn from_bytes_si(b: u64) -> Self {
         if b < KB {
             Self(Size::Bytes(b))
         } else if b < MB {
-            Self(Size::Kilo(b))
+            Self(Size::Kilo(b, System::Si))
         } else if b < GB {
-            Self(Size::Mega(b))
+            Self(Size::Mega(b, System::Si))
         } else if b < TB {
-            Self(Size::Giga(b))
+            Self(Size::Giga(b, System::Si))
+        } else if b < PB {
+            Self(Size::Tera(b, System::Si))
+        } else if b < EB {
+            Self(Size::Peta(b, System::Si))
+        } else {
+            Self(Size::Exa(b, System::Si))
+        }
+    }
+
+    /// Creates a [ByteSize] from a given number of bytes, normalizing
+    /// to the IEC (binary) unit system.
+    #[inline(always)]
+    pub const fn from_bytes_iec(b: u64) -> Self {
+        if b < KIB {
+            Self(Size::Bytes(b))
+        } else if b < MIB {
+            Self(Size::Kilo(b, System::Iec))
+        } else if b < GIB {
+            Self(Size::Mega(b, System::Iec))
+        } else if b < TIB {
+            Self(Size::Giga(b, System::Iec))
+        } else if b < PIB {
+            Self(Size::Tera(b, System::Iec))
+        } else if b < EIB {
+            Self(Size::Peta(b, System::Iec))
         } else {
-            Self(Size::Tera(b))
+            Self(Size::Exa(b, System::Iec))
+        }
+    }
+
+    /// Creates a [ByteSize] from a given number of **kibibytes** (binary, 1024-based).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use huby::ByteSize;
+    ///
+    /// assert_eq!(ByteSize::from_kib(1024), ByteSize::from_mib(1));
+    /// assert_eq!(ByteSize::from_kib(4096), ByteSize::from_mib(4));
+    /// ```
+    #[inline(always)]
+    pub const fn from_kib(kib: u64) -> Self {
+        Self::from_bytes_iec(kib * KIB)
+    }
+
+    /// Creates a [ByteSize] from a given number of **kibibytes** (binary, 1024-based).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use huby::ByteSize;
+    ///
+    /// assert_eq!(ByteSize::from_kib_f64(1.5), ByteSize::from_kib(1) + ByteSize::from_bytes(512));
+    /// ```
+    #[inline(always)]
+    pub fn from_kib_f64(kib: f64) -> Self {
+        Self::from_bytes_iec((kib * KIB as f64).round() as u64)
+    }
+
+    /// See [ByteSize::from_kib], only change is the parameter is expressed in MiB
+    #[inline(always)]
+    pub const fn from_mib(mib: u64) -> Self {
+        Self::from_bytes_iec(mib * MIB)
+    }
+
+    /// See [ByteSize::from_kib_f64], only change is the parameter is expressed in MiB
+    #[inline(always)]
+    pub fn from_mib_f64(mib: f64) -> Self {
+        Self::from_bytes_iec((mib * MIB as f64).round() as u64)
+    }
+
+    /// See [ByteSize::from_kib], only change is the parameter is expressed in GiB
+    #[inline(always)]
+    pub const fn from_gib(gib: u64) -> Self {
+        Self::from_bytes_iec(gib * GIB)
+    }
+
+    /// See [ByteSize::from_kib_f64], only change is the parameter is expressed in GiB
+    #[inline(always)]
+    pub fn from_gib_f64(gib: f64) -> Self {
+        Self::from_bytes_iec((gib * GIB as f64).round() as u64)
+    }
+
+    /// See [ByteSize::from_kib], only change is the parameter is expressed in TiB
+    #[inline(always)]
+    pub const fn from_tib(tib: u64) -> Self {
+        Self::from_bytes_iec(tib * TIB)
+    }
+
+    /// See [ByteSize::from_kib_f64], only change is the parameter is expressed in TiB
+    #[inline(always)]
+    pub fn from_tib_f64(tib: f64) -> Self {
+        Self::from_bytes_iec((tib * TIB as f64).round() as u64)
+    }
+
+    /// See [ByteSize::from_kib], only change is the parameter is expressed in PiB
+    #[inline(always)]
+    pub const fn from_pib(pib: u64) -> Self {
+        Self::from_bytes_iec(pib * PIB)
+    }
+
+    /// See [ByteSize::from_kib_f64], only change is the parameter is expressed in PiB
+    #[inline(always)]
+    pub fn from_pib_f64(pib: f64) -> Self {
+        Self::from_bytes_iec((pib * PIB as f64).round() as u64)
+    }
+
+    /// See [ByteSize::from_kib], only change is the parameter is expressed in EiB.
+    ///
+    /// Because `EIB` is close to `u64::MAX`, this multiplies with plain `*`
+    /// and overflows for `eib` greater than `15`. See [ByteSize::checked_from_eib]
+    /// for a variant that reports overflow instead.
+    #[inline(always)]
+    pub const fn from_eib(eib: u64) -> Self {
+        Self::from_bytes_iec(eib * EIB)
+    }
+
+    /// See [ByteSize::from_kib_f64], only change is the parameter is expressed in EiB
+    #[inline(always)]
+    pub fn from_eib_f64(eib: f64) -> Self {
+        Self::from_bytes_iec((eib * EIB as f64).round() as u64)
+    }
+
+    /// Same as [ByteSize::from_kib], but returns `None` instead of overflowing
+    /// when `kib * KIB` does not fit in a `u64`.
+    #[inline(always)]
+    pub const fn checked_from_kib(kib: u64) -> Option<Self> {
+        match kib.checked_mul(KIB) {
+            Some(b) => Some(Self::from_bytes_iec(b)),
+            None => None,
+        }
+    }
+
+    /// Same as [ByteSize::from_mib], but returns `None` instead of overflowing
+    /// when `mib * MIB` does not fit in a `u64`.
+    #[inline(always)]
+    pub const fn checked_from_mib(mib: u64) -> Option<Self> {
+        match mib.checked_mul(MIB) {
+            Some(b) => Some(Self::from_bytes_iec(b)),
+            None => None,
+        }
+    }
+
+    /// Same as [ByteSize::from_gib], but returns `None` instead of overflowing
+    /// when `gib * GIB` does not fit in a `u64`.
+    #[inline(always)]
+    pub const fn checked_from_gib(gib: u64) -> Option<Self> {
+        match gib.checked_mul(GIB) {
+            Some(b) => Some(Self::from_bytes_iec(b)),
+            None => None,
+        }
+    }
+
+    /// Same as [ByteSize::from_tib], but returns `None` instead of overflowing
+    /// when `tib * TIB` does not fit in a `u64`.
+    #[inline(always)]
+    pub const fn checked_from_tib(tib: u64) -> Option<Self> {
+        match tib.checked_mul(TIB) {
+            Some(b) => Some(Self::from_bytes_iec(b)),
+            None => None,
+        }
+    }
+
+    /// Same as [ByteSize::from_pib], but returns `None` instead of overflowing
+    /// when `pib * PIB` does not fit in a `u64`.
+    #[inline(always)]
+    pub const fn checked_from_pib(pib: u64) -> Option<Self> {
+        match pib.checked_mul(PIB) {
+            Some(b) => Some(Self::from_bytes_iec(b)),
+            None => None,
+        }
+    }
+
+    /// Same as [ByteSize::from_eib], but returns `None` instead of overflowing
+    /// when `eib * EIB` does not fit in a `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use huby::ByteSize;
+    ///
+    /// assert!(ByteSize::checked_from_eib(16).is_none());
+    /// assert!(ByteSize::checked_from_eib(1).is_some());
+    /// ```
+    #[inline(always)]
+    pub const fn checked_from_eib(eib: u64) -> Option<Self> {
+        match eib.checked_mul(EIB) {
+            Some(b) => Some(Self::from_bytes_iec(b)),
+            None => None,
         }
     }
 
-    /// Creates a [ByteSize] from a given number of **kilo bytes**.
+    /// Creates a [ByteSize] from a given number of **kilobytes** (SI, decimal, 1000-based).
     ///
     /// # Example
     ///
     /// ```
     /// use huby::ByteSize;
     ///
-    /// assert_eq!(ByteSize::from_kb(1024), ByteSize::from_mb(1));
-    /// assert_eq!(ByteSize::from_kb(4096), ByteSize::from_mb(4));
+    /// assert_eq!(ByteSize::from_kb(1000), ByteSize::from_mb(1));
+    /// assert_eq!(ByteSize::from_kb(4000), ByteSize::from_mb(4));
     /// ```
     #[inline(always)]
     pub const fn from_kb(kb: u64) -> Self {
-        Self::from_bytes(kb * KB)
+        Self::from_bytes_si(kb * KB)
     }
 
-    /// Creates a [ByteSize] from a given number of **kilo bytes**.
+    /// Creates a [ByteSize] from a given number of **kilobytes** (SI, decimal, 1000-based).
     ///
     /// # Example
     ///
     /// ```
     /// use huby::ByteSize;
     ///
-    /// assert_eq!(ByteSize::from_kb_f64(1.5), ByteSize::from_kb(1) + ByteSize::from_bytes(512));
+    /// assert_eq!(ByteSize::from_kb_f64(1.5), ByteSize::from_kb(1) + ByteSize::from_bytes(500));
     /// ```
     #[inline(always)]
     pub fn from_kb_f64(kb: f64) -> Self {
-        Self::from_bytes((kb * KB as f64).round() as u64)
+        Self::from_bytes_si((kb * KB as f64).round() as u64)
     }
 
     /// See [ByteSize::from_kb], only change is the parameter is expressed in MB
     #[inline(always)]
     pub const fn from_mb(mb: u64) -> Self {
-        Self::from_bytes(mb * MB)
+        Self::from_bytes_si(mb * MB)
     }
 
     /// See [ByteSize::from_kb_f64], only change is the parameter is expressed in MB
     #[inline(always)]
     pub fn from_mb_f64(mb: f64) -> Self {
-        Self::from_bytes((mb * MB as f64).round() as u64)
+        Self::from_bytes_si((mb * MB as f64).round() as u64)
     }
 
     /// See [ByteSize::from_kb], only change is the parameter is expressed in GB
     #[inline(always)]
     pub const fn from_gb(gb: u64) -> Self {
-        Self::from_bytes(gb * GB)
+        Self::from_bytes_si(gb * GB)
     }
 
     /// See [ByteSize::from_kb_f64], only change is the parameter is expressed in GB
     #[inline(always)]
     pub fn from_gb_f64(gb: f64) -> Self {
-        Self::from_bytes((gb * GB as f64).round() as u64)
+        Self::from_bytes_si((gb * GB as f64).round() as u64)
     }
 
     /// See [ByteSize::from_kb], only change is the parameter is expressed in TB
     #[inline(always)]
-    pub const fn from_tb(gb: u64) -> Self {
-        Self::from_bytes(gb * TB)
+    pub const fn from_tb(tb: u64) -> Self {
+        Self::from_bytes_si(tb * TB)
     }
 
     /// See [ByteSize::from_kb_f64], only change is the parameter is expressed in TB
     #[inline(always)]
-    pub fn from_tb_f64(gb: f64) -> Self {
-        Self::from_bytes((gb * TB as f64).round() as u64)
+    pub fn from_tb_f64(tb: f64) -> Self {
+        Self::from_bytes_si((tb * TB as f64).round() as u64)
+    }
+
+    /// See [ByteSize::from_kb], only change is the parameter is expressed in PB
+    #[inline(always)]
+    pub const fn from_pb(pb: u64) -> Self {
+        Self::from_bytes_si(pb * PB)
+    }
+
+    /// See [ByteSize::from_kb_f64], only change is the parameter is expressed in PB
+    #[inline(always)]
+    pub fn from_pb_f64(pb: f64) -> Self {
+        Self::from_bytes_si((pb * PB as f64).round() as u64)
+    }
+
+    /// See [ByteSize::from_kb], only change is the parameter is expressed in EB.
+    ///
+    /// Because `EB` is close to `u64::MAX`, this multiplies with plain `*`
+    /// and overflows for `eb` greater than `18`. See [ByteSize::checked_from_eb]
+    /// for a variant that reports overflow instead.
+    #[inline(always)]
+    pub const fn from_eb(eb: u64) -> Self {
+        Self::from_bytes_si(eb * EB)
+    }
+
+    /// See [ByteSize::from_kb_f64], only change is the parameter is expressed in EB
+    #[inline(always)]
+    pub fn from_eb_f64(eb: f64) -> Self {
+        Self::from_bytes_si((eb * EB as f64).round() as u64)
+    }
+
+    /// Same as [ByteSize::from_kb], but returns `None` instead of overflowing
+    /// when `kb * KB` does not fit in a `u64`.
+    #[inline(always)]
+    pub const fn checked_from_kb(kb: u64) -> Option<Self> {
+        match kb.checked_mul(KB) {
+            Some(b) => Some(Self::from_bytes_si(b)),
+            None => None,
+        }
+    }
+
+    /// Same as [ByteSize::from_mb], but returns `None` instead of overflowing
+    /// when `mb * MB` does not fit in a `u64`.
+    #[inline(always)]
+    pub const fn checked_from_mb(mb: u64) -> Option<Self> {
+        match mb.checked_mul(MB) {
+            Some(b) => Some(Self::from_bytes_si(b)),
+            None => None,
+        }
+    }
+
+    /// Same as [ByteSize::from_gb], but returns `None` instead of overflowing
+    /// when `gb * GB` does not fit in a `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use huby::ByteSize;
+    ///
+    /// assert!(ByteSize::checked_from_gb(u64::MAX / 2).is_none());
+    /// assert!(ByteSize::checked_from_gb(1).is_some());
+    /// ```
+    #[inline(always)]
+    pub const fn checked_from_gb(gb: u64) -> Option<Self> {
+        match gb.checked_mul(GB) {
+            Some(b) => Some(Self::from_bytes_si(b)),
+            None => None,
+        }
+    }
+
+    /// Same as [ByteSize::from_tb], but returns `None` instead of overflowing
+    /// when `tb * TB` does not fit in a `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use huby::ByteSize;
+    ///
+    /// assert!(ByteSize::checked_from_tb(u64::MAX / 2).is_none());
+    /// assert!(ByteSize::checked_from_tb(1).is_some());
+    /// ```
+    #[inline(always)]
+    pub const fn checked_from_tb(tb: u64) -> Option<Self> {
+        match tb.checked_mul(TB) {
+            Some(b) => Some(Self::from_bytes_si(b)),
+            None => None,
+        }
+    }
+
+    /// Same as [ByteSize::from_pb], but returns `None` instead of overflowing
+    /// when `pb * PB` does not fit in a `u64`.
+    #[inline(always)]
+    pub const fn checked_from_pb(pb: u64) -> Option<Self> {
+        match pb.checked_mul(PB) {
+            Some(b) => Some(Self::from_bytes_si(b)),
+            None => None,
+        }
+    }
+
+    /// Same as [ByteSize::from_eb], but returns `None` instead of overflowing
+    /// when `eb * EB` does not fit in a `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use huby::ByteSize;
+    ///
+    /// assert!(ByteSize::checked_from_eb(u64::MAX / 2).is_none());
+    /// assert!(ByteSize::checked_from_eb(1).is_some());
+    /// ```
+    #[inline(always)]
+    pub const fn checked_from_eb(eb: u64) -> Option<Self> {
+        match eb.checked_mul(EB) {
+            Some(b) => Some(Self::from_bytes_si(b)),
+            None => None,
+        }
     }
 
     #[inline(always)]
     const fn unit_str(&self) -> &'static str {
         match self.0 {
             Size::Bytes(_) => "B",
-            Size::Kilo(_) => "KB",
-            Size::Mega(_) => "MB",
-            Size::Giga(_) => "GB",
-            Size::Tera(_) => "TB",
+            Size::Kilo(_, System::Si) => "kB",
+            Size::Kilo(_, System::Iec) => "KiB",
+            Size::Mega(_, System::Si) => "MB",
+            Size::Mega(_, System::Iec) => "MiB",
+            Size::Giga(_, System::Si) => "GB",
+            Size::Giga(_, System::Iec) => "GiB",
+            Size::Tera(_, System::Si) => "TB",
+            Size::Tera(_, System::Iec) => "TiB",
+            Size::Peta(_, System::Si) => "PB",
+            Size::Peta(_, System::Iec) => "PiB",
+            Size::Exa(_, System::Si) => "EB",
+            Size::Exa(_, System::Iec) => "EiB",
         }
     }
 
@@ -233,16 +706,18 @@ impl ByteSize {
     /// ```
     /// use huby::ByteSize;
     ///
-    /// assert_eq!(ByteSize::from_kb_f64(1.5).in_bytes(), 1536);
+    /// assert_eq!(ByteSize::from_kib_f64(1.5).in_bytes(), 1536);
     /// ```
     #[inline(always)]
     pub const fn in_bytes(&self) -> u64 {
         match self.0 {
             Size::Bytes(b) => b,
-            Size::Kilo(b) => b,
-            Size::Mega(b) => b,
-            Size::Giga(b) => b,
-            Size::Tera(b) => b,
+            Size::Kilo(b, _) => b,
+            Size::Mega(b, _) => b,
+            Size::Giga(b, _) => b,
+            Size::Tera(b, _) => b,
+            Size::Peta(b, _) => b,
+            Size::Exa(b, _) => b,
         }
     }
 
@@ -255,10 +730,12 @@ impl ByteSize {
     /// ```
     /// use huby::ByteSize;
     ///
-    /// let kb = ByteSize::from_kb(1);
-    /// assert_eq!(kb.to_string(), "1.0KB");
+    /// let kb = ByteSize::from_kib(1);
+    /// assert_eq!(kb.to_string(), "1.0KiB");
     /// assert_eq!(kb.in_bytes(), 1024);
-    /// assert_eq!(kb.into_bytes().to_string(), "1024.0B");
+    /// // `to_string` normalizes to the best unit by default, so forcing
+    /// // the raw byte count requires disabling normalization explicitly.
+    /// assert_eq!(kb.into_bytes().display().normalize(false).to_string(), "1024.0B");
     /// assert_eq!(kb.in_bytes(), 1024);
     /// ```
     #[inline(always)]
@@ -266,54 +743,111 @@ impl ByteSize {
         Self(Size::Bytes(self.in_bytes()))
     }
 
-    /// See [ByteSize::into_bytes]
+    /// See [ByteSize::into_bytes], labels the value as IEC kibibytes
+    #[inline(always)]
+    pub const fn into_kib(self) -> Self {
+        Self(Size::Kilo(self.in_bytes(), System::Iec))
+    }
+
+    /// See [ByteSize::into_bytes], labels the value as IEC mebibytes
+    #[inline(always)]
+    pub const fn into_mib(self) -> Self {
+        Self(Size::Mega(self.in_bytes(), System::Iec))
+    }
+
+    /// See [ByteSize::into_bytes], labels the value as IEC gibibytes
+    #[inline(always)]
+    pub const fn into_gib(self) -> Self {
+        Self(Size::Giga(self.in_bytes(), System::Iec))
+    }
+
+    /// See [ByteSize::into_bytes], labels the value as IEC tebibytes
+    #[inline(always)]
+    pub const fn into_tib(self) -> Self {
+        Self(Size::Tera(self.in_bytes(), System::Iec))
+    }
+
+    /// See [ByteSize::into_bytes], labels the value as IEC pebibytes
+    #[inline(always)]
+    pub const fn into_pib(self) -> Self {
+        Self(Size::Peta(self.in_bytes(), System::Iec))
+    }
+
+    /// See [ByteSize::into_bytes], labels the value as IEC exbibytes
+    #[inline(always)]
+    pub const fn into_eib(self) -> Self {
+        Self(Size::Exa(self.in_bytes(), System::Iec))
+    }
+
+    /// See [ByteSize::into_bytes], labels the value as SI kilobytes
     #[inline(always)]
     pub const fn into_kb(self) -> Self {
-        Self(Size::Kilo(self.in_bytes()))
+        Self(Size::Kilo(self.in_bytes(), System::Si))
     }
 
-    /// See [ByteSize::into_bytes]
+    /// See [ByteSize::into_bytes], labels the value as SI megabytes
     #[inline(always)]
     pub const fn into_mb(self) -> Self {
-        Self(Size::Mega(self.in_bytes()))
+        Self(Size::Mega(self.in_bytes(), System::Si))
     }
 
-    /// See [ByteSize::into_bytes]
+    /// See [ByteSize::into_bytes], labels the value as SI gigabytes
     #[inline(always)]
     pub const fn into_gb(self) -> Self {
-        Self(Size::Giga(self.in_bytes()))
+        Self(Size::Giga(self.in_bytes(), System::Si))
     }
 
-    /// See [ByteSize::into_bytes]
+    /// See [ByteSize::into_bytes], labels the value as SI terabytes
     #[inline(always)]
     pub const fn into_tb(self) -> Self {
-        Self(Size::Tera(self.in_bytes()))
+        Self(Size::Tera(self.in_bytes(), System::Si))
+    }
+
+    /// See [ByteSize::into_bytes], labels the value as SI petabytes
+    #[inline(always)]
+    pub const fn into_pb(self) -> Self {
+        Self(Size::Peta(self.in_bytes(), System::Si))
+    }
+
+    /// See [ByteSize::into_bytes], labels the value as SI exabytes
+    #[inline(always)]
+    pub const fn into_eb(self) -> Self {
+        Self(Size::Exa(self.in_bytes(), System::Si))
     }
 
     #[inline(always)]
     const fn divisor(&self) -> f64 {
         match self.0 {
             Size::Bytes(_) => 1.0,
-            Size::Kilo(_) => KB as f64,
-            Size::Mega(_) => MB as f64,
-            Size::Giga(_) => GB as f64,
-            Size::Tera(_) => TB as f64,
+            Size::Kilo(_, System::Si) => KB as f64,
+            Size::Kilo(_, System::Iec) => KIB as f64,
+            Size::Mega(_, System::Si) => MB as f64,
+            Size::Mega(_, System::Iec) => MIB as f64,
+            Size::Giga(_, System::Si) => GB as f64,
+            Size::Giga(_, System::Iec) => GIB as f64,
+            Size::Tera(_, System::Si) => TB as f64,
+            Size::Tera(_, System::Iec) => TIB as f64,
+            Size::Peta(_, System::Si) => PB as f64,
+            Size::Peta(_, System::Iec) => PIB as f64,
+            Size::Exa(_, System::Si) => EB as f64,
+            Size::Exa(_, System::Iec) => EIB as f64,
         }
     }
 
-    /// Normalizes [ByteSize] to fit in the best variant
+    /// Normalizes [ByteSize] to fit in the best variant of the IEC
+    /// (binary) unit system, whatever system the value was expressed in.
     ///
     /// # Example
     ///
     /// ```
     /// use huby::{ByteSize, Unit};
     ///
-    /// // the best to represent 2048KB is 2MB  
-    /// assert!(matches!(ByteSize::from_kb(2048).unit(), Unit::Mega))
+    /// // the best to represent 2048KiB is 2MiB
+    /// assert!(matches!(ByteSize::from_kib(2048).unit(), Unit::Mega))
     /// ```
     #[inline(always)]
     pub const fn normalize(self) -> Self {
-        Self::from_bytes(self.in_bytes())
+        Self::from_bytes_iec(self.in_bytes())
     }
 
     /// Returns the value of [ByteSize] expressed in the
@@ -324,10 +858,10 @@ impl ByteSize {
     /// ```
     /// use huby::ByteSize;
     ///
-    /// assert_eq!(ByteSize::from_kb_f64(1.5).in_unit(), 1.5);
-    /// assert_eq!(ByteSize::from_kb_f64(1024.0).into_mb().in_unit(), 1.0);
-    /// assert_eq!(ByteSize::from_mb_f64(1024.0).into_gb().in_unit(), 1.0);
-    /// assert_eq!(ByteSize::from_gb_f64(1024.0).into_tb().in_unit(), 1.0);
+    /// assert_eq!(ByteSize::from_kib_f64(1.5).in_unit(), 1.5);
+    /// assert_eq!(ByteSize::from_kib_f64(1024.0).into_mib().in_unit(), 1.0);
+    /// assert_eq!(ByteSize::from_mib_f64(1024.0).into_gib().in_unit(), 1.0);
+    /// assert_eq!(ByteSize::from_gib_f64(1024.0).into_tib().in_unit(), 1.0);
     /// ```
     #[inline(always)]
     pub fn in_unit(&self) -> f64 {
@@ -355,9 +889,79 @@ mod test {
         assert_eq!((a + ByteSize::from_gb(1)).in_bytes(), GB + 42)
     }
 
+    #[test]
+    fn test_checked_saturating_add_sub() {
+        let max = ByteSize::from_bytes(u64::MAX);
+        let one = ByteSize::from_bytes(1);
+        let zero = ByteSize::from_bytes(0);
+
+        assert!(max.checked_add(one).is_none());
+        assert!(zero.checked_sub(one).is_none());
+        assert_eq!(max.saturating_add(one), max);
+        assert_eq!(zero.saturating_sub(one), zero);
+
+        // operators never panic, they saturate
+        assert_eq!(max + one, max);
+        assert_eq!(zero - one, zero);
+    }
+
+    #[test]
+    fn test_mul_div() {
+        let mut a = ByteSize::from_bytes(10);
+        assert_eq!(a * 3, ByteSize::from_bytes(30));
+        assert_eq!(a * 1.5, ByteSize::from_bytes(15));
+        a *= 3;
+        assert_eq!(a, ByteSize::from_bytes(30));
+        a /= 3;
+        assert_eq!(a, ByteSize::from_bytes(10));
+        assert_eq!(a / 2, ByteSize::from_bytes(5));
+        assert_eq!(ByteSize::from_bytes(30) / ByteSize::from_bytes(10), 3.0);
+
+        // scalar Mul/Div never panic, they saturate
+        let max = ByteSize::from_bytes(u64::MAX);
+        assert_eq!(max * 2u64, max);
+        assert_eq!(a / 0, ByteSize::from_bytes(u64::MAX));
+    }
+
     #[test]
     fn test_into_other_units() {
         let b = ByteSize::from_gb(1000);
         println!("{}", b.into_kb())
     }
+
+    #[test]
+    fn test_si_and_iec_are_distinct() {
+        assert_eq!(ByteSize::from_kb(1).in_bytes(), 1000);
+        assert_eq!(ByteSize::from_kib(1).in_bytes(), 1024);
+        assert_ne!(ByteSize::from_kb(1), ByteSize::from_kib(1));
+    }
+
+    #[test]
+    fn test_peta_exa() {
+        assert_eq!(ByteSize::from_pib(1024), ByteSize::from_eib(1));
+        assert_eq!(ByteSize::from_pb(1000), ByteSize::from_eb(1));
+        assert!(ByteSize::checked_from_eib(16).is_none());
+        assert!(ByteSize::checked_from_eb(u64::MAX / 2).is_none());
+        assert_eq!(
+            ByteSize::checked_from_eib(1),
+            Some(ByteSize::from_eib(1))
+        );
+    }
+
+    #[test]
+    fn test_checked_from_lower_tiers() {
+        assert_eq!(ByteSize::checked_from_kb(1), Some(ByteSize::from_kb(1)));
+        assert_eq!(ByteSize::checked_from_mb(1), Some(ByteSize::from_mb(1)));
+        assert_eq!(ByteSize::checked_from_gb(1), Some(ByteSize::from_gb(1)));
+        assert_eq!(ByteSize::checked_from_tb(1), Some(ByteSize::from_tb(1)));
+        assert!(ByteSize::checked_from_gb(u64::MAX / 2).is_none());
+        assert!(ByteSize::checked_from_tb(u64::MAX / 2).is_none());
+
+        assert_eq!(ByteSize::checked_from_kib(1), Some(ByteSize::from_kib(1)));
+        assert_eq!(ByteSize::checked_from_mib(1), Some(ByteSize::from_mib(1)));
+        assert_eq!(ByteSize::checked_from_gib(1), Some(ByteSize::from_gib(1)));
+        assert_eq!(ByteSize::checked_from_tib(1), Some(ByteSize::from_tib(1)));
+        assert!(ByteSize::checked_from_gib(u64::MAX / 2).is_none());
+        assert!(ByteSize::checked_from_tib(u64::MAX / 2).is_none());
+    }
 }
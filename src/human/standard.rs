@@ -75,16 +75,116 @@ impl Debug for ByteSize {
 
 impl Display for ByteSize {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let bs = {
-            // if we are below 1.0 it means we are lacking precision
-            // so we must somehow normalize the byte value
-            if self.in_unit() < 1.0 {
-                self.normalize()
-            } else {
-                *self
+        Display::fmt(&self.display(), f)
+    }
+}
+
+/// Builder controlling how a [ByteSize] gets rendered: precision,
+/// whether to normalize to the best-fitting unit, the unit system
+/// (SI/IEC), and whether to insert a space before the suffix. Build
+/// one with [ByteSize::display]; it implements [Display] directly, so
+/// it composes with `write!`/`format!` without allocating.
+///
+/// # Example
+///
+/// ```
+/// use huby::{ByteSize, System};
+///
+/// let size = ByteSize::from_gb(518);
+/// assert_eq!(size.display().to_string(), "518.0GB");
+/// assert_eq!(size.display().precision(0).space(true).to_string(), "518 GB");
+/// assert_eq!(size.display().system(System::Iec).to_string(), "482.4GiB");
+/// ```
+#[derive(Clone, Copy)]
+pub struct Format {
+    size: ByteSize,
+    precision: usize,
+    normalize: bool,
+    space: bool,
+    system: Option<System>,
+}
+
+impl Format {
+    /// Sets the number of digits after the decimal point (default: `1`).
+    pub const fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Whether to pick the best-fitting unit for the value (default: `true`),
+    /// as opposed to keeping the unit the value is currently labeled as.
+    pub const fn normalize(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Whether to insert a space before the unit suffix, e.g. `"518 GB"`
+    /// instead of `"518GB"` (default: `false`).
+    pub const fn space(mut self, space: bool) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Forces rendering in the given [System], overriding the system the
+    /// value was originally expressed in (default: keep the original system).
+    pub const fn system(mut self, system: System) -> Self {
+        self.system = Some(system);
+        self
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let system = self
+            .system
+            .or(match self.size.0 {
+                Size::Bytes(_) => None,
+                Size::Kilo(_, s)
+                | Size::Mega(_, s)
+                | Size::Giga(_, s)
+                | Size::Tera(_, s)
+                | Size::Peta(_, s)
+                | Size::Exa(_, s) => Some(s),
+            })
+            .unwrap_or(System::Iec);
+
+        let bs = if self.normalize {
+            match system {
+                System::Si => ByteSize::from_bytes_si(self.size.in_bytes()),
+                System::Iec => ByteSize::from_bytes_iec(self.size.in_bytes()),
+            }
+        } else {
+            let b = self.size.in_bytes();
+            match self.size.0 {
+                Size::Bytes(_) => ByteSize(Size::Bytes(b)),
+                Size::Kilo(..) => ByteSize(Size::Kilo(b, system)),
+                Size::Mega(..) => ByteSize(Size::Mega(b, system)),
+                Size::Giga(..) => ByteSize(Size::Giga(b, system)),
+                Size::Tera(..) => ByteSize(Size::Tera(b, system)),
+                Size::Peta(..) => ByteSize(Size::Peta(b, system)),
+                Size::Exa(..) => ByteSize(Size::Exa(b, system)),
             }
         };
-        write!(f, "{:.1}{}", bs.in_unit(), bs.unit_str())
+
+        if self.space {
+            write!(f, "{:.*} {}", self.precision, bs.in_unit(), bs.unit_str())
+        } else {
+            write!(f, "{:.*}{}", self.precision, bs.in_unit(), bs.unit_str())
+        }
+    }
+}
+
+impl ByteSize {
+    /// Returns a [Format] builder to configure precision, normalization,
+    /// unit system, and spacing when rendering this value.
+    pub const fn display(&self) -> Format {
+        Format {
+            size: *self,
+            precision: 1,
+            normalize: true,
+            space: false,
+            system: None,
+        }
     }
 }
 
@@ -107,10 +207,35 @@ impl ByteSize {
         let f = s.trim_end_matches('0').trim_end_matches('.');
         format!("{}{}", f, bs.unit_str())
     }
+
+    /// Renders this [ByteSize], forcing either the SI (decimal, `si = true`)
+    /// or the IEC (binary, `si = false`) unit system, regardless of the
+    /// system the value was originally expressed in.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use huby::ByteSize;
+    ///
+    /// let size = ByteSize::from_kib(1);
+    /// assert_eq!(size.to_string_si(false), "1.0KiB");
+    /// assert_eq!(size.to_string_si(true), "1.0kB");
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_string_si(&self, si: bool) -> String {
+        let bs = if si {
+            Self::from_bytes_si(self.in_bytes())
+        } else {
+            Self::from_bytes_iec(self.in_bytes())
+        };
+        format!("{:.1}{}", bs.in_unit(), bs.unit_str())
+    }
 }
 
 #[derive(Error, Debug)]
 pub enum ParseError {
+    #[error("empty or invalid number: {0}")]
+    InvalidNumber(String),
     #[error("unknown unit {0}")]
     UnkUnit(String),
     #[error("parse int: {0}")]
@@ -122,24 +247,43 @@ pub enum ParseError {
 impl FromStr for ByteSize {
     type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.ends_with("KB") {
-            Ok(Self::from_kb_f64(
-                s.trim_end_matches("KB").trim().parse::<f64>()?,
-            ))
-        } else if s.ends_with("MB") {
-            Ok(Self::from_mb_f64(
-                s.trim_end_matches("MB").trim().parse::<f64>()?,
-            ))
-        } else if s.ends_with("GB") {
-            Ok(Self::from_gb_f64(
-                s.trim_end_matches("GB").trim().parse::<f64>()?,
-            ))
-        } else if s.ends_with('B') {
-            Ok(Self::from_bytes(
-                s.trim_end_matches('B').trim().parse::<u64>()?,
-            ))
-        } else {
-            Err(ParseError::UnkUnit(s.into()))
+        let s = s.trim();
+
+        // first, try interpreting the whole string as a bare number of bytes
+        if let Ok(b) = s.parse::<u64>() {
+            return Ok(Self::from_bytes(b));
+        }
+
+        let split_at = s
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(s.len());
+        let (num, unit) = s.split_at(split_at);
+        let unit = unit.trim();
+
+        if num.is_empty() {
+            return Err(ParseError::InvalidNumber(s.into()));
+        }
+
+        match unit.to_ascii_lowercase().as_str() {
+            // matching is case-insensitive, so "b"/"B" (bytes) and a hypothetical
+            // single-letter bit suffix collide here; bytes wins deliberately since
+            // "B" was already the byte suffix before bits were supported, and the
+            // unambiguous "bit" suffix is still available for the ÷8 conversion.
+            "" | "b" => Ok(Self::from_bytes(num.parse::<u64>()?)),
+            "bit" => Ok(Self::from_bits_uncheked(num.parse::<u64>()?)),
+            "kb" => Ok(Self::from_kb_f64(num.parse::<f64>()?)),
+            "kib" => Ok(Self::from_kib_f64(num.parse::<f64>()?)),
+            "mb" => Ok(Self::from_mb_f64(num.parse::<f64>()?)),
+            "mib" => Ok(Self::from_mib_f64(num.parse::<f64>()?)),
+            "gb" => Ok(Self::from_gb_f64(num.parse::<f64>()?)),
+            "gib" => Ok(Self::from_gib_f64(num.parse::<f64>()?)),
+            "tb" => Ok(Self::from_tb_f64(num.parse::<f64>()?)),
+            "tib" => Ok(Self::from_tib_f64(num.parse::<f64>()?)),
+            "pb" => Ok(Self::from_pb_f64(num.parse::<f64>()?)),
+            "pib" => Ok(Self::from_pib_f64(num.parse::<f64>()?)),
+            "eb" => Ok(Self::from_eb_f64(num.parse::<f64>()?)),
+            "eib" => Ok(Self::from_eib_f64(num.parse::<f64>()?)),
+            _ => Err(ParseError::UnkUnit(unit.into())),
         }
     }
 }
@@ -148,6 +292,7 @@ impl FromStr for ByteSize {
 mod test {
     use serde::{Deserialize, Serialize};
 
+    use super::ParseError;
     use crate::ByteSize;
 
     #[test]
@@ -167,11 +312,77 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_from_str_si_and_iec() {
+        // bare integers are bytes
+        assert_eq!("4096".parse::<ByteSize>().unwrap(), ByteSize::from_bytes(4096));
+        // SI (decimal) suffixes
+        assert_eq!("1 kB".parse::<ByteSize>().unwrap(), ByteSize::from_kb(1));
+        assert_eq!("1.5gb".parse::<ByteSize>().unwrap(), ByteSize::from_gb_f64(1.5));
+        // IEC (binary) suffixes, case-insensitive
+        assert_eq!("1 KiB".parse::<ByteSize>().unwrap(), ByteSize::from_kib(1));
+        assert_eq!("1MIB".parse::<ByteSize>().unwrap(), ByteSize::from_mib(1));
+    }
+
+    #[test]
+    fn test_from_str_permissive() {
+        // bare numbers, whitespace-tolerant suffixes
+        assert_eq!("4096".parse::<ByteSize>().unwrap(), ByteSize::from_bytes(4096));
+        assert_eq!(
+            "10 MiB".parse::<ByteSize>().unwrap(),
+            ByteSize::from_mib_f64(10.0)
+        );
+        assert_eq!(
+            "1.5 gb".parse::<ByteSize>().unwrap(),
+            ByteSize::from_gb_f64(1.5)
+        );
+        // bits
+        assert_eq!(
+            "800 bit".parse::<ByteSize>().unwrap(),
+            ByteSize::from_bits_uncheked(800)
+        );
+        // errors distinguish an invalid number from an unknown unit
+        assert!(matches!(
+            "MiB".parse::<ByteSize>(),
+            Err(ParseError::InvalidNumber(_))
+        ));
+        assert!(matches!(
+            "10 XB".parse::<ByteSize>(),
+            Err(ParseError::UnkUnit(_))
+        ));
+    }
+
     #[test]
     fn test_to_string_prec() {
-        // we try to print some KB as TB. Since resolution is very low
-        // to_string_with_prec must make the value KB again for display
-        assert_eq!(ByteSize::from_kb(1).into_tb().to_string_with_prec(2), "1KB");
+        // we try to print some KiB as TiB. Since resolution is very low
+        // to_string_with_prec must make the value KiB again for display
+        assert_eq!(
+            ByteSize::from_kib(1).into_tib().to_string_with_prec(2),
+            "1KiB"
+        );
+    }
+
+    #[test]
+    fn test_display_format() {
+        use crate::System;
+
+        let size = ByteSize::from_gb(518);
+        assert_eq!(size.display().to_string(), "518.0GB");
+        assert_eq!(size.display().precision(0).to_string(), "518GB");
+        assert_eq!(size.display().space(true).to_string(), "518.0 GB");
+        assert_eq!(size.display().system(System::Iec).to_string(), "482.4GiB");
+        // keep the stored tier instead of normalizing to the best one
+        assert_eq!(
+            ByteSize::from_bytes(1024).into_kb().display().normalize(false).to_string(),
+            "1.0kB"
+        );
+    }
+
+    #[test]
+    fn test_to_string_si() {
+        let size = ByteSize::from_kib(1);
+        assert_eq!(size.to_string_si(false), "1.0KiB");
+        assert_eq!(size.to_string_si(true), "1.0kB");
     }
 
     #[test]
@@ -186,7 +397,7 @@ mod test {
         };
 
         let ser = serde_json::to_string(&t).unwrap();
-        assert_eq!(ser, r#"{"a":"10.42KB"}"#);
+        assert_eq!(ser, r#"{"a":"10.42kB"}"#);
 
         let de: T = serde_json::from_str(&ser).unwrap();
         assert_eq!(de.a, ByteSize::from_kb_f64(10.42))
@@ -43,7 +43,7 @@
 //!
 //! // Deserialize
 //! let l: Logger = serde_json::from_str(&j).unwrap();
-//! assert_eq!(l.max_size, ByteSize::from_mb(1024));
+//! assert_eq!(l.max_size, ByteSize::from_mb(1000));
 //! ```
 
 mod human;